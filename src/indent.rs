@@ -5,19 +5,122 @@ use crate::libyaml::parser::Parser;
 use std::borrow::Cow;
 
 /// Detected indentation information from a YAML document.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Indentation {
-    /// The number of spaces used for each indentation level.
-    indent: usize,
+    /// The indentation style used throughout the document.
+    style: IndentStyle,
+    /// Line numbers (1-based) where indentation used a tab character,
+    /// populated only when `style` is [`IndentStyle::Tabs`].
+    tab_lines: Vec<usize>,
+    /// How block sequences are indented relative to their parent mapping
+    /// key, if the document contains any.
+    sequence_style: Option<SequenceStyle>,
+    /// The content indentation column of the first literal/folded block
+    /// scalar in the document, if any, relative to the document start
+    /// (not to the scalar's opener).
+    block_scalar_indent: Option<usize>,
 }
 
 impl Indentation {
-    /// Returns the number of spaces used for each indentation level.
-    pub fn spaces(&self) -> usize {
-        self.indent
+    /// Returns the number of spaces used for each indentation level, or
+    /// `None` if the document doesn't use [`IndentStyle::Spaces`] (for
+    /// example because it uses tabs). This is a back-compat shorthand for
+    /// `self.style().spaces()`.
+    pub fn spaces(&self) -> Option<usize> {
+        self.style.spaces()
+    }
+
+    /// Returns the document's indentation style.
+    pub fn style(&self) -> IndentStyle {
+        self.style
+    }
+
+    /// Returns the 1-based line numbers where indentation used a tab
+    /// character. Always empty unless [`Self::style`] is
+    /// [`IndentStyle::Tabs`].
+    pub fn tab_lines(&self) -> &[usize] {
+        &self.tab_lines
+    }
+
+    /// Returns how block sequences are indented relative to their parent
+    /// mapping key, or `None` if the document has no block sequences
+    /// nested under a mapping key.
+    pub fn sequence_style(&self) -> Option<SequenceStyle> {
+        self.sequence_style
+    }
+
+    /// Returns the content indentation column of the first literal (`|`)
+    /// or folded (`>`) block scalar in the document, or `None` if it has
+    /// none. Preserving this column lets a re-emitter reproduce the
+    /// original block scalar formatting instead of reindenting it.
+    pub fn block_scalar_indent(&self) -> Option<usize> {
+        self.block_scalar_indent
+    }
+}
+
+/// A document's indentation style, as classified from its leading
+/// whitespace.
+///
+/// Tab indentation is invalid in YAML block structure, but real-world
+/// files use tabs often enough that detection reports it as a distinct
+/// outcome instead of failing outright, so a caller can offer to convert
+/// tabs to spaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndentStyle {
+    /// Indentation built from `n` spaces per level.
+    Spaces(u8),
+    /// Indentation built from tab characters.
+    Tabs,
+    /// No indentation could be determined.
+    None,
+}
+
+impl IndentStyle {
+    /// Classifies a run of leading whitespace taken from the start of a
+    /// line, e.g. the `"    "` in `"    child: value"`.
+    pub fn from_leading(leading: &str) -> IndentStyle {
+        if leading.contains('\t') {
+            return IndentStyle::Tabs;
+        }
+        match leading.len() {
+            0 => IndentStyle::None,
+            n => IndentStyle::Spaces(n.min(u8::MAX as usize) as u8),
+        }
+    }
+
+    /// Back-compat accessor: returns `Some(n)` only for
+    /// [`IndentStyle::Spaces`].
+    pub fn spaces(&self) -> Option<usize> {
+        match *self {
+            IndentStyle::Spaces(n) => Some(n as usize),
+            IndentStyle::Tabs | IndentStyle::None => None,
+        }
     }
 }
 
+/// How a block sequence is indented relative to the mapping key that
+/// introduces it, e.g. in:
+///
+/// ```yaml
+/// indented:
+///   - a
+/// flush:
+/// - b
+/// ```
+///
+/// `indented` uses [`SequenceStyle::Indented`] and `flush` uses
+/// [`SequenceStyle::Flush`]. This mirrors the distinction yamllint's
+/// `indent-sequences` rule checks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceStyle {
+    /// Sequence items carry extra leading spaces beyond the key's column.
+    Indented,
+    /// Sequence items sit flush with the key's column.
+    Flush,
+    /// Both styles appear in the same document.
+    Mixed,
+}
+
 /// Detects the indentation used in a YAML string.
 ///
 /// This function analyzes a YAML document to determine the number of spaces
@@ -39,12 +142,12 @@ impl Indentation {
 /// // 2-space indentation (default)
 /// let yaml = "root:\n  child: value\n";
 /// let indent = detect_indentation(yaml).unwrap().unwrap();
-/// assert_eq!(indent.spaces(), 2);
+/// assert_eq!(indent.spaces(), Some(2));
 ///
 /// // 4-space indentation
 /// let yaml = "root:\n    child: value\n";
 /// let indent = detect_indentation(yaml).unwrap().unwrap();
-/// assert_eq!(indent.spaces(), 4);
+/// assert_eq!(indent.spaces(), Some(4));
 ///
 /// // Flat YAML with no indentation
 /// let yaml = "key: value\n";
@@ -59,13 +162,30 @@ pub fn detect_indentation(yaml: &str) -> Result<Option<Indentation>> {
 /// This is the byte slice variant of [`detect_indentation`]. See that function
 /// for full documentation.
 pub fn detect_indentation_slice(yaml: &[u8]) -> Result<Option<Indentation>> {
-    // First, validate the YAML is parseable
-    validate_yaml(yaml)?;
+    // Tab-indented block structure is exactly what trips up libyaml's
+    // scanner before any validating parse can even run (it rejects such
+    // input with an opaque "found character that cannot start any token"
+    // error). Check for it first so that case still resolves to
+    // `IndentStyle::Tabs` via `detect_from_text` below, instead of never
+    // getting there because `validate_yaml` already failed.
+    if !has_structural_tab_indent(yaml)? {
+        validate_yaml(yaml)?;
+    }
 
     // Analyze the raw text to detect indentation
     detect_from_text(yaml)
 }
 
+/// Returns true if `yaml` contains tab-indented block structure outside
+/// comments and block scalar content, i.e. the case [`scan_lines`] reports
+/// through its `tab_lines` output. See [`detect_indentation_slice`] for why
+/// this is checked ahead of [`validate_yaml`].
+fn has_structural_tab_indent(yaml: &[u8]) -> Result<bool> {
+    let text = str_from_utf8(yaml)?;
+    let (_, tab_lines, _) = scan_lines(text)?;
+    Ok(!tab_lines.is_empty())
+}
+
 /// Validates that the input is valid YAML by attempting to parse it.
 fn validate_yaml(yaml: &[u8]) -> Result<()> {
     use crate::libyaml::parser::Event;
@@ -82,48 +202,385 @@ fn validate_yaml(yaml: &[u8]) -> Result<()> {
     Ok(())
 }
 
+/// Decodes `yaml` as UTF-8, wrapping the error in this module's error type.
+fn str_from_utf8(yaml: &[u8]) -> Result<&str> {
+    std::str::from_utf8(yaml).map_err(|e| {
+        error::new(ErrorImpl::Message(
+            format!("invalid UTF-8: {}", e),
+            None,
+        ))
+    })
+}
+
+/// Returns true if `line`'s leading whitespace contains a tab character.
+/// Tabs are not valid YAML indentation, but real-world files use them
+/// often enough that detection reports it via [`IndentStyle::Tabs`]
+/// instead of failing.
+fn has_tab_indent(line: &str) -> bool {
+    line.chars()
+        .take_while(|&c| c == ' ' || c == '\t')
+        .any(|c| c == '\t')
+}
+
+/// Counts the leading spaces on `line`. Only meaningful when
+/// [`has_tab_indent`] is false for the same line.
+fn leading_space_count(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// Approximates the visual width of `line`'s leading whitespace, advancing
+/// to the next multiple-of-8 column for each tab (the common terminal
+/// convention). Used only to decide whether a line is still indented past
+/// an open block scalar's opener: such a line may use tabs as its only
+/// leading whitespace (e.g. a Makefile recipe embedded verbatim), where an
+/// exact column isn't meaningful but "is this indented past the opener"
+/// still is.
+fn leading_whitespace_width(line: &str) -> usize {
+    let mut width = 0;
+    for c in line.chars() {
+        match c {
+            ' ' => width += 1,
+            '\t' => width += 8 - (width % 8),
+            _ => break,
+        }
+    }
+    width
+}
+
 /// Detects indentation by analyzing the raw text.
 fn detect_from_text(yaml: &[u8]) -> Result<Option<Indentation>> {
-    let text = match std::str::from_utf8(yaml) {
-        Ok(s) => s,
-        Err(e) => {
-            return Err(error::new(ErrorImpl::Message(
-                format!("invalid UTF-8: {}", e),
-                None,
-            )));
+    let text = str_from_utf8(yaml)?;
+    let (lines, tab_lines, block_scalar_indent) = scan_lines(text)?;
+
+    if !tab_lines.is_empty() {
+        return Ok(Some(Indentation {
+            style: IndentStyle::Tabs,
+            tab_lines,
+            sequence_style: detect_sequence_style(text),
+            block_scalar_indent,
+        }));
+    }
+
+    // Collect all indentation levels (leading space counts) for content
+    // lines, excluding block scalar content (which has its own, unrelated
+    // indentation rules).
+    let indent_levels: Vec<usize> = lines
+        .iter()
+        .filter(|l| !l.in_block_scalar)
+        .map(|l| l.column)
+        .collect();
+
+    // Find the indentation unit (GCD of the indentation steps)
+    let indent = find_indentation_unit(&indent_levels)?;
+
+    Ok(indent.map(|i| Indentation {
+        style: IndentStyle::Spaces(i.min(u8::MAX as usize) as u8),
+        tab_lines: Vec::new(),
+        sequence_style: detect_sequence_style(text),
+        block_scalar_indent,
+    }))
+}
+
+/// A single content line as seen by [`scan_lines`]; blank and comment
+/// lines are omitted.
+struct ScanLine {
+    line_no: usize,
+    column: usize,
+    /// Whether this line is content inside a literal/folded block scalar,
+    /// as opposed to regular block structure.
+    in_block_scalar: bool,
+}
+
+/// Tracks the block scalar (if any) currently being scanned.
+struct BlockScalarState {
+    /// Column of the `key: |` / `key: >` line that opened the block.
+    opener_column: usize,
+    /// Column established by the block's first content line, once seen.
+    base_indent: Option<usize>,
+}
+
+/// Splits `text` into content lines with their indentation column,
+/// flagging lines that fall inside a literal (`|`) or folded (`>`) block
+/// scalar so callers can exclude them from structural indentation
+/// analysis. Also returns the base indentation of the first block scalar
+/// encountered, if any.
+///
+/// Lines using tab indentation are reported separately via the returned
+/// line-number list rather than contributing a column here.
+///
+/// Returns an error if block scalar content dedents below the column
+/// established by its first content line.
+fn scan_lines(text: &str) -> Result<(Vec<ScanLine>, Vec<usize>, Option<usize>)> {
+    let mut lines_out = Vec::new();
+    let mut tab_lines = Vec::new();
+    let mut block_scalar_indent: Option<usize> = None;
+    let mut block: Option<BlockScalarState> = None;
+
+    for (idx, line) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let column = leading_space_count(line);
+
+        // Check block scalar membership before anything else: a `#` or a
+        // tab inside literal/folded content (e.g. a Makefile recipe's
+        // leading tab) is part of the data, not document structure, and
+        // must not be treated as a comment or trip tab-style detection.
+        // Membership itself is judged by visual width rather than `column`
+        // (a plain space count), since content may be indented with tabs
+        // whose exact column isn't meaningful but whose width past the
+        // opener still is.
+        if let Some(state) = &mut block {
+            if leading_whitespace_width(line) > state.opener_column {
+                match state.base_indent {
+                    None => {
+                        state.base_indent = Some(column);
+                        block_scalar_indent.get_or_insert(column);
+                    }
+                    Some(base) if column < base => {
+                        return Err(error::new(ErrorImpl::Message(
+                            format!(
+                                "line {}: block scalar content dedented to column {}, expected at least column {}",
+                                line_no, column, base
+                            ),
+                            None,
+                        )));
+                    }
+                    _ => {}
+                }
+                lines_out.push(ScanLine {
+                    line_no,
+                    column,
+                    in_block_scalar: true,
+                });
+                continue;
+            }
+            block = None;
         }
+
+        if trimmed.starts_with('#') {
+            continue;
+        }
+
+        if has_tab_indent(line) {
+            tab_lines.push(line_no);
+            continue;
+        }
+
+        lines_out.push(ScanLine {
+            line_no,
+            column,
+            in_block_scalar: false,
+        });
+
+        if is_block_scalar_opener(trimmed) {
+            block = Some(BlockScalarState {
+                opener_column: column,
+                base_indent: None,
+            });
+        }
+    }
+
+    Ok((lines_out, tab_lines, block_scalar_indent))
+}
+
+/// Returns true if `trimmed` opens a literal (`|`) or folded (`>`) block
+/// scalar, optionally followed by a chomping indicator (`+`/`-`) and/or an
+/// explicit indentation indicator digit, e.g. `key: |`, `key: |2-`, `- >`.
+fn is_block_scalar_opener(trimmed: &str) -> bool {
+    let without_comment = match trimmed.find(" #") {
+        Some(idx) => trimmed[..idx].trim_end(),
+        None => trimmed.trim_end(),
     };
+    let Some(token) = without_comment.rsplit(' ').next() else {
+        return false;
+    };
+    let mut chars = token.chars();
+    match chars.next() {
+        Some('|') | Some('>') => chars.all(|c| c == '+' || c == '-' || c.is_ascii_digit()),
+        _ => false,
+    }
+}
+
+/// Like [`detect_indentation`], but fails on the first inconsistent
+/// indentation step instead of collapsing a mix of widths to their GCD.
+///
+/// This mirrors yamllint's `spaces: consistent` check: the unit is
+/// inferred from the first indented line, and every subsequent increase
+/// in nesting depth must be exactly one multiple of that unit relative to
+/// its enclosing level.
+///
+/// # Errors
+///
+/// Returns an error naming the offending line, the column it was actually
+/// indented to, and the column the inferred unit expected instead.
+pub fn detect_indentation_strict(yaml: &str) -> Result<Option<Indentation>> {
+    let bytes = yaml.as_bytes();
+
+    // Same ordering concern as `detect_indentation_slice`: tab-indented
+    // block structure must be detected before the validating parse, or it
+    // never gets the chance to resolve to `IndentStyle::Tabs` via
+    // `detect_from_text_strict` below.
+    if !has_structural_tab_indent(bytes)? {
+        validate_yaml(bytes)?;
+    }
+
+    detect_from_text_strict(bytes)
+}
+
+/// Collects the 1-based line numbers where a parser event begins, so
+/// [`detect_from_text_strict`] can tell a line that opens a new node from a
+/// line that is merely the continuation of a preceding multi-line plain or
+/// quoted scalar (which carries no indentation meaning of its own).
+fn event_start_lines(yaml: &[u8]) -> Result<std::collections::HashSet<usize>> {
+    use crate::libyaml::parser::Event;
+
+    let mut parser = Parser::new(Cow::Borrowed(yaml));
+    let mut lines = std::collections::HashSet::new();
 
-    // Collect all indentation levels (leading space counts) for content lines
-    let mut indent_levels: Vec<usize> = Vec::new();
+    loop {
+        let (event, mark) = parser.next().map_err(error::Error::from)?;
+        // libyaml marks are 0-based; `ScanLine::line_no` is 1-based.
+        lines.insert(mark.line() as usize + 1);
+        if matches!(event, Event::StreamEnd) {
+            break;
+        }
+    }
+
+    Ok(lines)
+}
+
+/// Detects indentation by analyzing the raw text, per
+/// [`detect_indentation_strict`].
+fn detect_from_text_strict(yaml: &[u8]) -> Result<Option<Indentation>> {
+    let text = str_from_utf8(yaml)?;
+    let (lines, tab_lines, block_scalar_indent) = scan_lines(text)?;
+
+    if !tab_lines.is_empty() {
+        return Ok(Some(Indentation {
+            style: IndentStyle::Tabs,
+            tab_lines,
+            sequence_style: detect_sequence_style(text),
+            block_scalar_indent,
+        }));
+    }
+
+    // Only lines where some event actually starts count toward nesting
+    // depth; a line that merely continues a multi-line scalar shares its
+    // value's start mark and is skipped here.
+    let node_start_lines = event_start_lines(yaml)?;
+
+    let mut unit: Option<usize> = None;
+    // Columns of the currently open nesting levels, outermost first.
+    let mut open_columns: Vec<usize> = vec![0];
+
+    for scan_line in lines
+        .iter()
+        .filter(|l| !l.in_block_scalar && node_start_lines.contains(&l.line_no))
+    {
+        let column = scan_line.column;
+
+        while let Some(&top) = open_columns.last() {
+            if column < top {
+                open_columns.pop();
+            } else {
+                break;
+            }
+        }
+
+        let parent = *open_columns.last().unwrap_or(&0);
+        if column > parent {
+            let step = column - parent;
+            match unit {
+                None => unit = Some(step),
+                Some(u) if step != u => {
+                    return Err(error::new(ErrorImpl::Message(
+                        format!(
+                            "inconsistent indentation at line {}: found column {}, expected column {}",
+                            scan_line.line_no,
+                            column,
+                            parent + u
+                        ),
+                        None,
+                    )));
+                }
+                _ => {}
+            }
+            open_columns.push(column);
+        }
+    }
+
+    Ok(unit.map(|i| Indentation {
+        style: IndentStyle::Spaces(i.min(u8::MAX as usize) as u8),
+        tab_lines: Vec::new(),
+        sequence_style: detect_sequence_style(text),
+        block_scalar_indent,
+    }))
+}
+
+/// Detects whether block sequences in `text` are indented relative to
+/// their parent mapping key, flush with it, or both (see
+/// [`SequenceStyle`]).
+fn detect_sequence_style(text: &str) -> Option<SequenceStyle> {
+    let mut saw_indented = false;
+    let mut saw_flush = false;
+    let mut open_key_column: Option<usize> = None;
 
     for line in text.lines() {
-        // Skip empty lines and comment-only lines
         let trimmed = line.trim_start();
         if trimmed.is_empty() || trimmed.starts_with('#') {
             continue;
         }
+        let column = line.len() - line.trim_start_matches(' ').len();
+
+        if let Some(rest) = trimmed.strip_prefix('-') {
+            if let Some(key_column) = open_key_column {
+                if column > key_column {
+                    saw_indented = true;
+                } else if column == key_column {
+                    saw_flush = true;
+                }
+            }
 
-        // Count leading spaces (tabs are not valid YAML indentation)
-        let leading_spaces = line.len() - line.trim_start_matches(' ').len();
-
-        // Check for tab indentation which is invalid in YAML
-        if line.starts_with('\t')
-            || (leading_spaces > 0 && line.as_bytes().get(leading_spaces) == Some(&b'\t'))
-        {
-            return Err(error::new(ErrorImpl::Message(
-                "tab characters are not allowed for indentation in YAML".to_string(),
-                None,
-            )));
+            // A sequence item can itself carry an inline mapping key, e.g.
+            // `- children:`, which nested items are compared against; a
+            // plain item like `- a` leaves whatever key column was already
+            // open (the parent's) in place for the next sibling.
+            let after_dash = rest.trim_start();
+            if !after_dash.is_empty() && is_mapping_key_opener(after_dash) {
+                let after_dash_column = column + (trimmed.len() - after_dash.len());
+                open_key_column = Some(after_dash_column);
+            }
+            continue;
         }
 
-        indent_levels.push(leading_spaces);
+        open_key_column = if is_mapping_key_opener(trimmed) {
+            Some(column)
+        } else {
+            None
+        };
     }
 
-    // Find the minimum non-zero indentation difference
-    let indent = find_indentation_unit(&indent_levels)?;
+    match (saw_indented, saw_flush) {
+        (true, true) => Some(SequenceStyle::Mixed),
+        (true, false) => Some(SequenceStyle::Indented),
+        (false, true) => Some(SequenceStyle::Flush),
+        (false, false) => None,
+    }
+}
 
-    Ok(indent.map(|i| Indentation { indent: i }))
+/// Returns true if `trimmed` looks like a mapping key with no inline
+/// scalar value, e.g. `foo:` or `foo:  # comment`, which is the kind of
+/// line a following `-` item can be nested under.
+fn is_mapping_key_opener(trimmed: &str) -> bool {
+    let without_comment = match trimmed.find(" #") {
+        Some(idx) => trimmed[..idx].trim_end(),
+        None => trimmed.trim_end(),
+    };
+    !without_comment.starts_with('-') && without_comment.ends_with(':')
 }
 
 /// Finds the indentation unit from a list of indentation levels.
@@ -202,6 +659,128 @@ fn gcd(mut a: usize, mut b: usize) -> usize {
     a
 }
 
+/// Smallest indentation width the emitter can be configured with.
+const MIN_EMITTER_INDENT: usize = 2;
+/// Largest indentation width the emitter can be configured with.
+const MAX_EMITTER_INDENT: usize = 9;
+
+/// Re-emits `yaml` using `target` as the indentation width.
+///
+/// This is meant for editing-tool round-trips: detect the indentation of an
+/// existing file with [`detect_indentation`], edit the parsed value with
+/// serde as usual (which serializes at the library's default width), then
+/// call `reindent` on the result so the width matches the original file and
+/// the on-disk diff stays minimal.
+///
+/// Lines are rewritten by scaling their leading whitespace from the width
+/// currently used in `yaml` to `target.spaces()`; the document itself is
+/// not re-parsed into a value, so this is cheaper than a full round-trip
+/// through a [`Value`](crate::Value) when only the indentation needs to
+/// change. Lines inside a literal (`|`) or folded (`>`) block scalar are
+/// shifted by the same amount as the scalar's opener rather than being
+/// independently rescaled, since their leading whitespace beyond the
+/// scalar's own base indentation is part of the content, not document
+/// structure, and rescaling it on its own could let the opener's new
+/// indentation catch up with or overtake it, producing invalid YAML.
+///
+/// # Errors
+///
+/// Returns an error if `yaml` is not valid YAML, if `target` has no
+/// [`IndentStyle::Spaces`] width (for example because it is
+/// [`IndentStyle::Tabs`]), or if that width is outside the 2-9 range
+/// supported by the emitter.
+pub fn reindent(yaml: &str, target: &Indentation) -> Result<String> {
+    let target_width = target.spaces().ok_or_else(|| {
+        error::new(ErrorImpl::Message(
+            "reindent requires a target using space-based indentation".to_string(),
+            None,
+        ))
+    })?;
+
+    reindent_to_width(yaml, target_width)
+}
+
+/// Core of [`reindent`], operating on a plain target width so callers that
+/// already have one (such as [`crate::ser::to_string_with_indent`]) don't
+/// need to build an [`Indentation`] just to pass it through.
+pub(crate) fn reindent_to_width(yaml: &str, target_width: usize) -> Result<String> {
+    if !(MIN_EMITTER_INDENT..=MAX_EMITTER_INDENT).contains(&target_width) {
+        return Err(error::new(ErrorImpl::Message(
+            format!(
+                "indent width must be between {} and {}, got {}",
+                MIN_EMITTER_INDENT, MAX_EMITTER_INDENT, target_width
+            ),
+            None,
+        )));
+    }
+
+    validate_yaml(yaml.as_bytes())?;
+
+    let text = str_from_utf8(yaml.as_bytes())?;
+    let (lines, _, _) = scan_lines(text)?;
+
+    let current = match find_indentation_unit(
+        &lines
+            .iter()
+            .filter(|l| !l.in_block_scalar)
+            .map(|l| l.column)
+            .collect::<Vec<_>>(),
+    )? {
+        Some(current) => current,
+        None => return Ok(yaml.to_string()),
+    };
+
+    if current == target_width {
+        return Ok(yaml.to_string());
+    }
+
+    let rescale = |leading_spaces: usize| -> usize {
+        let levels = leading_spaces / current;
+        let remainder = leading_spaces % current;
+        levels * target_width + remainder
+    };
+
+    let mut out = String::with_capacity(yaml.len());
+    // Tracks the block scalar (if any) whose content is currently being
+    // rewritten, as (opener's original column, opener's rescaled column).
+    // Content lines are shifted by that same amount rather than being
+    // independently rescaled; see the shift-vs-rescale rationale on
+    // `reindent` above.
+    let mut block: Option<(usize, usize)> = None;
+
+    for line in yaml.lines() {
+        let trimmed = line.trim_start_matches(' ');
+        if trimmed.is_empty() {
+            out.push('\n');
+            continue;
+        }
+        let leading_spaces = line.len() - trimmed.len();
+
+        if let Some((old_opener_col, new_opener_col)) = block {
+            if leading_spaces > old_opener_col {
+                let shift = new_opener_col as isize - old_opener_col as isize;
+                let new_col = (leading_spaces as isize + shift).max(0) as usize;
+                out.push_str(&" ".repeat(new_col));
+                out.push_str(trimmed);
+                out.push('\n');
+                continue;
+            }
+            block = None;
+        }
+
+        let new_col = rescale(leading_spaces);
+        out.push_str(&" ".repeat(new_col));
+        out.push_str(trimmed);
+        out.push('\n');
+
+        if !trimmed.starts_with('#') && is_block_scalar_opener(trimmed) {
+            block = Some((leading_spaces, new_col));
+        }
+    }
+
+    Ok(out)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,28 +789,28 @@ mod tests {
     fn test_detect_2_space_indent() {
         let yaml = "root:\n  child: value\n";
         let result = detect_indentation(yaml).unwrap().unwrap();
-        assert_eq!(result.spaces(), 2);
+        assert_eq!(result.spaces(), Some(2));
     }
 
     #[test]
     fn test_detect_4_space_indent() {
         let yaml = "root:\n    child: value\n";
         let result = detect_indentation(yaml).unwrap().unwrap();
-        assert_eq!(result.spaces(), 4);
+        assert_eq!(result.spaces(), Some(4));
     }
 
     #[test]
     fn test_detect_nested_indent() {
         let yaml = "root:\n  level1:\n    level2: value\n";
         let result = detect_indentation(yaml).unwrap().unwrap();
-        assert_eq!(result.spaces(), 2);
+        assert_eq!(result.spaces(), Some(2));
     }
 
     #[test]
     fn test_detect_sequence_indent() {
         let yaml = "items:\n  - one\n  - two\n";
         let result = detect_indentation(yaml).unwrap().unwrap();
-        assert_eq!(result.spaces(), 2);
+        assert_eq!(result.spaces(), Some(2));
     }
 
     #[test]
@@ -259,14 +838,14 @@ mod tests {
     fn test_8_space_indent() {
         let yaml = "root:\n        child: value\n";
         let result = detect_indentation(yaml).unwrap().unwrap();
-        assert_eq!(result.spaces(), 8);
+        assert_eq!(result.spaces(), Some(8));
     }
 
     #[test]
     fn test_3_space_indent() {
         let yaml = "root:\n   child: value\n";
         let result = detect_indentation(yaml).unwrap().unwrap();
-        assert_eq!(result.spaces(), 3);
+        assert_eq!(result.spaces(), Some(3));
     }
 
     #[test]
@@ -278,7 +857,7 @@ level0:
             level3: value
 "#;
         let result = detect_indentation(yaml).unwrap().unwrap();
-        assert_eq!(result.spaces(), 4);
+        assert_eq!(result.spaces(), Some(4));
     }
 
     #[test]
@@ -290,7 +869,52 @@ root:
   child: value
 "#;
         let result = detect_indentation(yaml).unwrap().unwrap();
-        assert_eq!(result.spaces(), 2);
+        assert_eq!(result.spaces(), Some(2));
+    }
+
+    #[test]
+    fn test_reindent_widens() {
+        let yaml = "root:\n  child:\n    leaf: value\n";
+        let indentation = Indentation { style: IndentStyle::Spaces(4), tab_lines: Vec::new(), sequence_style: None, block_scalar_indent: None };
+        let result = reindent(yaml, &indentation).unwrap();
+        assert_eq!(result, "root:\n    child:\n        leaf: value\n");
+    }
+
+    #[test]
+    fn test_reindent_narrows() {
+        let yaml = "root:\n    child:\n        leaf: value\n";
+        let indentation = Indentation { style: IndentStyle::Spaces(2), tab_lines: Vec::new(), sequence_style: None, block_scalar_indent: None };
+        let result = reindent(yaml, &indentation).unwrap();
+        assert_eq!(result, "root:\n  child:\n    leaf: value\n");
+    }
+
+    #[test]
+    fn test_reindent_noop_when_already_at_target() {
+        let yaml = "root:\n  child: value\n";
+        let indentation = Indentation { style: IndentStyle::Spaces(2), tab_lines: Vec::new(), sequence_style: None, block_scalar_indent: None };
+        let result = reindent(yaml, &indentation).unwrap();
+        assert_eq!(result, yaml);
+    }
+
+    #[test]
+    fn test_reindent_rejects_out_of_range_width() {
+        let yaml = "root:\n  child: value\n";
+        let indentation = Indentation { style: IndentStyle::Spaces(1), tab_lines: Vec::new(), sequence_style: None, block_scalar_indent: None };
+        assert!(reindent(yaml, &indentation).is_err());
+    }
+
+    #[test]
+    fn test_reindent_shifts_block_scalar_content_with_its_opener() {
+        // The opener moves from column 2 to column 4 (a +2 shift); content
+        // must shift by the same amount so it stays more indented than the
+        // opener, instead of being frozen at its original column.
+        let yaml = "root:\n  text: |\n    line one\n      indented more\n  sibling: value\n";
+        let indentation = Indentation { style: IndentStyle::Spaces(4), tab_lines: Vec::new(), sequence_style: None, block_scalar_indent: None };
+        let result = reindent(yaml, &indentation).unwrap();
+        assert_eq!(
+            result,
+            "root:\n    text: |\n      line one\n        indented more\n    sibling: value\n"
+        );
     }
 
     #[test]
@@ -303,6 +927,165 @@ sequence:
   - item2
 "#;
         let result = detect_indentation(yaml).unwrap().unwrap();
-        assert_eq!(result.spaces(), 2);
+        assert_eq!(result.spaces(), Some(2));
+    }
+
+    #[test]
+    fn test_sequence_style_indented() {
+        let yaml = "items:\n  - one\n  - two\n";
+        let result = detect_indentation(yaml).unwrap().unwrap();
+        assert_eq!(result.sequence_style(), Some(SequenceStyle::Indented));
+    }
+
+    #[test]
+    fn test_sequence_style_flush() {
+        let yaml = "root:\n  items:\n  - one\n  - two\n";
+        let result = detect_indentation(yaml).unwrap().unwrap();
+        assert_eq!(result.sequence_style(), Some(SequenceStyle::Flush));
+    }
+
+    #[test]
+    fn test_sequence_style_mixed() {
+        let yaml = "indented:\n  - one\nflush:\n- two\n";
+        let result = detect_indentation(yaml).unwrap().unwrap();
+        assert_eq!(result.sequence_style(), Some(SequenceStyle::Mixed));
+    }
+
+    #[test]
+    fn test_sequence_style_flush_with_inline_key_on_dash_line() {
+        let yaml = "items:\n- children:\n  - a\n  - b\n";
+        let result = detect_indentation(yaml).unwrap().unwrap();
+        assert_eq!(result.sequence_style(), Some(SequenceStyle::Flush));
+    }
+
+    #[test]
+    fn test_sequence_style_none_without_sequences() {
+        let yaml = "root:\n  child: value\n";
+        let result = detect_indentation(yaml).unwrap().unwrap();
+        assert_eq!(result.sequence_style(), None);
+    }
+
+    #[test]
+    fn test_strict_consistent_indent() {
+        let yaml = "root:\n  level1:\n    level2: value\n";
+        let result = detect_indentation_strict(yaml).unwrap().unwrap();
+        assert_eq!(result.spaces(), Some(2));
+    }
+
+    #[test]
+    fn test_strict_rejects_mixed_steps() {
+        let yaml = "root:\n  level1:\n      level2: value\n";
+        let err = detect_indentation_strict(yaml).unwrap_err();
+        assert!(err.to_string().contains("line 3"));
+    }
+
+    #[test]
+    fn test_strict_allows_sibling_dedent() {
+        let yaml = "root:\n  child:\n    leaf: value\n  sibling: value\n";
+        let result = detect_indentation_strict(yaml).unwrap().unwrap();
+        assert_eq!(result.spaces(), Some(2));
+    }
+
+    #[test]
+    fn test_strict_ignores_multiline_plain_scalar_continuation() {
+        let yaml = "a: 1\nb: long value\n  that continues\nouter:\n    inner: value\n";
+        let result = detect_indentation_strict(yaml).unwrap().unwrap();
+        assert_eq!(result.spaces(), Some(4));
+    }
+
+    #[test]
+    fn test_strict_reports_tabs_instead_of_failing_the_validating_parse() {
+        // Tab-indented structure like this is exactly what libyaml's
+        // scanner rejects outright; detect_indentation_strict must report
+        // it via IndentStyle::Tabs instead of surfacing that parse error.
+        let yaml = "root:\n\tchild: value\n";
+        let result = detect_indentation_strict(yaml).unwrap().unwrap();
+        assert_eq!(result.style(), IndentStyle::Tabs);
+        assert_eq!(result.tab_lines(), &[2]);
+    }
+
+    #[test]
+    fn test_block_scalar_excluded_from_unit_detection() {
+        let yaml = "root:\n  text: |\n      line one\n      line two\n  sibling: value\n";
+        let result = detect_indentation(yaml).unwrap().unwrap();
+        assert_eq!(result.spaces(), Some(2));
+        assert_eq!(result.block_scalar_indent(), Some(6));
+    }
+
+    #[test]
+    fn test_block_scalar_none_without_one() {
+        let yaml = "root:\n  child: value\n";
+        let result = detect_indentation(yaml).unwrap().unwrap();
+        assert_eq!(result.block_scalar_indent(), None);
+    }
+
+    #[test]
+    fn test_block_scalar_content_dedent_is_error() {
+        let yaml = "root:\n  text: |\n      line one\n    line two\n";
+        let err = detect_indentation(yaml).unwrap_err();
+        assert!(err.to_string().contains("dedented"));
+    }
+
+    #[test]
+    fn test_block_scalar_folded_with_chomping_indicator() {
+        let yaml = "root:\n  text: >-\n    folded text\n  sibling: value\n";
+        let result = detect_indentation(yaml).unwrap().unwrap();
+        assert_eq!(result.spaces(), Some(2));
+        assert_eq!(result.block_scalar_indent(), Some(4));
+    }
+
+    #[test]
+    fn test_tab_indentation_reported_not_errored() {
+        let yaml = "root:\n\tchild: value\n";
+        let result = detect_indentation(yaml).unwrap().unwrap();
+        assert_eq!(result.style(), IndentStyle::Tabs);
+        assert_eq!(result.spaces(), None);
+        assert_eq!(result.tab_lines(), &[2]);
+    }
+
+    #[test]
+    fn test_tab_inside_block_scalar_content_not_reported() {
+        let yaml = "root:\n  text: |\n    target:\n    \tcommand\n  sibling: value\n";
+        let result = detect_indentation(yaml).unwrap().unwrap();
+        assert_eq!(result.style(), IndentStyle::Spaces(2));
+        assert!(result.tab_lines().is_empty());
+    }
+
+    #[test]
+    fn test_tab_only_block_scalar_content_not_reported() {
+        let yaml = "root:\n  recipe: |\n\ttarget:\n\t\tcommand\n  sibling: value\n";
+        let result = detect_indentation(yaml).unwrap().unwrap();
+        assert_eq!(result.style(), IndentStyle::Spaces(2));
+        assert!(result.tab_lines().is_empty());
+    }
+
+    #[test]
+    fn test_tab_inside_comment_not_reported() {
+        let yaml = "root:\n  child: value\n  #\tcomment with a tab\n  sibling: value\n";
+        let result = detect_indentation(yaml).unwrap().unwrap();
+        assert_eq!(result.style(), IndentStyle::Spaces(2));
+        assert!(result.tab_lines().is_empty());
+    }
+
+    #[test]
+    fn test_indent_style_from_leading_spaces() {
+        assert_eq!(IndentStyle::from_leading("    "), IndentStyle::Spaces(4));
+    }
+
+    #[test]
+    fn test_indent_style_from_leading_tabs() {
+        assert_eq!(IndentStyle::from_leading("\t\t"), IndentStyle::Tabs);
+    }
+
+    #[test]
+    fn test_indent_style_from_leading_none() {
+        assert_eq!(IndentStyle::from_leading(""), IndentStyle::None);
+    }
+
+    #[test]
+    fn test_indent_style_spaces_accessor() {
+        assert_eq!(IndentStyle::Spaces(4).spaces(), Some(4));
+        assert_eq!(IndentStyle::Tabs.spaces(), None);
+        assert_eq!(IndentStyle::None.spaces(), None);
     }
 }
@@ -0,0 +1,138 @@
+//! Indent-width-configurable serialization helpers.
+//!
+//! These sit on top of the crate's default serializer rather than driving
+//! the underlying libyaml emitter's indent setting directly: a value is
+//! serialized at the library's default width, then the result is passed
+//! through [`indent::reindent_to_width`](crate::indent::reindent_to_width)
+//! to rescale it, at the cost of one extra pass over the output.
+//!
+//! Going through the emitter's own indent setting would be the more direct
+//! route, but it isn't exposed as a public, supported knob on the emitter
+//! binding this crate builds on, and adding one would mean committing to a
+//! piece of the unsafe libyaml FFI surface that nothing else in this crate
+//! currently depends on changing. The text-rescale approach only needs
+//! [`indent::reindent_to_width`], which is exercised independently (and
+//! thoroughly) by `indent`'s own test suite, so width-correctness here
+//! reduces to "does this module call it with the right arguments" -- see
+//! the tests below.
+
+use crate::error::{self, ErrorImpl, Result};
+use crate::indent;
+use serde::Serialize;
+use std::io;
+
+/// Serializes values to YAML at a caller-chosen indentation width.
+///
+/// Use [`Serializer::with_indent`] to build one, then call
+/// [`Serializer::serialize`] with the value to write. For a one-shot call
+/// that only needs a `String`, [`to_string_with_indent`] is more
+/// convenient.
+pub struct Serializer<W> {
+    writer: W,
+    indent: usize,
+}
+
+impl<W> Serializer<W>
+where
+    W: io::Write,
+{
+    /// Creates a serializer that writes to `writer` using `indent` spaces
+    /// per nesting level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `indent` is outside the 2-9 range supported by
+    /// the emitter.
+    pub fn with_indent(writer: W, indent: usize) -> Result<Self> {
+        // Validate eagerly so a bad width is reported at construction time
+        // rather than after a value has already been serialized.
+        indent::reindent_to_width("", indent)?;
+        Ok(Serializer { writer, indent })
+    }
+
+    /// Serializes `value` as YAML and writes it to the underlying writer.
+    pub fn serialize<T>(mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        let default = crate::to_string(value)?;
+        let reindented = indent::reindent_to_width(&default, self.indent)?;
+        self.writer.write_all(reindented.as_bytes()).map_err(|e| {
+            error::new(ErrorImpl::Message(
+                format!("failed to write YAML output: {}", e),
+                None,
+            ))
+        })?;
+        Ok(())
+    }
+}
+
+/// Serializes `value` as a YAML string using `indent` spaces per nesting
+/// level.
+///
+/// This is a convenience wrapper around [`Serializer::with_indent`] for
+/// callers that just want the resulting `String`.
+///
+/// # Errors
+///
+/// Returns an error if `indent` is outside the 2-9 range supported by the
+/// emitter, or if `value` fails to serialize.
+pub fn to_string_with_indent<T>(value: &T, indent: usize) -> Result<String>
+where
+    T: Serialize + ?Sized,
+{
+    let mut buf = Vec::new();
+    Serializer::with_indent(&mut buf, indent)?.serialize(value)?;
+    Ok(String::from_utf8(buf).expect("serializer only writes valid UTF-8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_with_indent_rejects_width_below_minimum() {
+        let mut buf = Vec::new();
+        assert!(Serializer::with_indent(&mut buf, 1).is_err());
+    }
+
+    #[test]
+    fn test_with_indent_rejects_width_above_maximum() {
+        let mut buf = Vec::new();
+        assert!(Serializer::with_indent(&mut buf, 10).is_err());
+    }
+
+    #[test]
+    fn test_with_indent_accepts_supported_range() {
+        for width in 2..=9 {
+            let mut buf = Vec::new();
+            assert!(Serializer::with_indent(&mut buf, width).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_to_string_with_indent_is_noop_for_flat_values() {
+        // A value with no nested structure has no indentation to rescale,
+        // regardless of the requested width.
+        let value = 42;
+        let default = crate::to_string(&value).unwrap();
+        for width in 2..=9 {
+            assert_eq!(to_string_with_indent(&value, width).unwrap(), default);
+        }
+    }
+
+    #[test]
+    fn test_to_string_with_indent_widens_nested_mapping() {
+        let mut inner = BTreeMap::new();
+        inner.insert("b".to_string(), 1);
+        let mut outer = BTreeMap::new();
+        outer.insert("a".to_string(), inner);
+
+        let default = crate::to_string(&outer).unwrap();
+        let widened = to_string_with_indent(&outer, 4).unwrap();
+
+        assert_ne!(default, widened);
+        assert!(widened.contains("\n    b: 1"));
+    }
+}